@@ -0,0 +1,33 @@
+//! Export support for captured effect frames: since WASM has no
+//! filesystem to write to, "saving" a capture means handing the browser a
+//! `Blob` and clicking a throwaway anchor to trigger a download.
+
+use wasm_bindgen::{JsCast, JsValue};
+use web_sys::{Blob, BlobPropertyBag, HtmlAnchorElement, Url};
+
+/// Triggers a browser download of `contents` as `filename`.
+pub fn download_text_file(filename: &str, contents: &str) -> Result<(), JsValue> {
+    let parts = js_sys::Array::new();
+    parts.push(&JsValue::from_str(contents));
+
+    let properties = BlobPropertyBag::new();
+    properties.set_type("text/plain");
+    let blob = Blob::new_with_str_sequence_and_options(&parts, &properties)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let document = web_sys::window()
+        .and_then(|window| window.document())
+        .ok_or_else(|| JsValue::from_str("no document to trigger a download from"))?;
+
+    let anchor: HtmlAnchorElement = document
+        .create_element("a")?
+        .dyn_into()
+        .map_err(|_| JsValue::from_str("failed to create anchor element"))?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+
+    Url::revoke_object_url(&url)?;
+
+    Ok(())
+}
@@ -0,0 +1,272 @@
+//! A real propagating fire simulation (the classic PSX Doom fire algorithm),
+//! built as a custom buffer-level shader on top of tachyonfx's `effect_fn_buf`.
+//!
+//! Unlike an `evolve`/`CoalescePattern` reveal combined with an upward
+//! `translate`, intensity here genuinely propagates: each tick every cell
+//! samples the cell below it, decays, and jitters sideways, which is what
+//! gives the flame its organic flicker instead of a canned wipe.
+//!
+//! The simulation renders into its own offscreen `Buffer` (sized to the
+//! effect's area, not the whole frame) and is blitted onto the real frame
+//! buffer at a computed offset each tick. That keeps the fire's cell grid
+//! decoupled from wherever the caller happens to position it.
+
+use ratatui::{
+    buffer::Buffer,
+    layout::{Offset, Rect},
+    style::Color,
+};
+
+use tachyonfx::{blit_buffer, fx, Duration, Effect};
+
+/// Highest intensity in the fire ramp (seeded into the bottom row).
+const MAX_INTENSITY: u8 = 36;
+
+/// How often the propagation step runs, independent of frame rate.
+const STEP_INTERVAL: Duration = Duration::from_millis(40);
+
+/// Maps a 0..=36 intensity to a color along the black -> red -> orange ->
+/// yellow -> white ramp used by the original PSX Doom fire.
+fn palette_color(intensity: u8) -> Color {
+    match intensity {
+        0 => Color::from_u32(0x07_0707),
+        1..=6 => Color::from_u32(0x1f_0707),
+        7..=12 => Color::from_u32(0x4f_2707),
+        13..=18 => Color::from_u32(0x87_1f07),
+        19..=24 => Color::from_u32(0xc7_4607),
+        25..=29 => Color::from_u32(0xdf_9f07),
+        30..=33 => Color::from_u32(0xef_bf07),
+        _ => Color::from_u32(0xff_ff9f),
+    }
+}
+
+/// Maps intensity to a block glyph so the fire reads as a shape even
+/// without color (and gives the embers a bit of texture up close).
+fn palette_glyph(intensity: u8) -> char {
+    match intensity {
+        0 => ' ',
+        1..=12 => '░',
+        13..=24 => '▒',
+        25..=30 => '▓',
+        _ => '█',
+    }
+}
+
+/// xorshift32 PRNG, used purely for the sideways decay jitter - no need to
+/// pull in a `rand` dependency for a handful of `& 3` rolls per cell.
+#[derive(Clone)]
+struct Xorshift32(u32);
+
+impl Xorshift32 {
+    fn next(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+}
+
+#[derive(Clone)]
+struct FireState {
+    grid: Vec<u8>,
+    width: usize,
+    height: usize,
+    rng: Xorshift32,
+    fade_out: bool,
+    since_step: Duration,
+    /// Detached buffer the fire renders into; blitted onto the frame buffer
+    /// each tick rather than written into directly.
+    offscreen: Buffer,
+}
+
+impl FireState {
+    fn new(area: Rect, fade_out: bool) -> Self {
+        let width = area.width.max(1) as usize;
+        let height = area.height.max(1) as usize;
+        let mut grid = vec![0u8; width * height];
+        seed_bottom_row(&mut grid, width, height);
+
+        let offscreen = Buffer::empty(Rect::new(0, 0, width as u16, height as u16));
+
+        Self {
+            grid,
+            width,
+            height,
+            rng: Xorshift32(0x9E3779B9),
+            fade_out,
+            since_step: Duration::ZERO,
+            offscreen,
+        }
+    }
+
+    fn step(&mut self) {
+        if self.height == 0 {
+            return;
+        }
+
+        if self.fade_out {
+            let bottom = (self.height - 1) * self.width;
+            for cell in &mut self.grid[bottom..bottom + self.width] {
+                *cell = cell.saturating_sub(1);
+            }
+        }
+
+        for y in (0..self.height.saturating_sub(1)).rev() {
+            for x in 0..self.width {
+                let idx = y * self.width + x;
+                let src = self.grid[idx + self.width];
+                if src == 0 {
+                    self.grid[idx] = 0;
+                    continue;
+                }
+
+                let decay = (self.rng.next() & 3) as u8;
+                let new_intensity = src.saturating_sub(decay & 1);
+
+                // Horizontal jitter in {-1, 0, 1, 2} (decay is 0..=3) is
+                // what gives the flame its flicker and "wind" rather than
+                // a straight column.
+                let jitter = decay as isize - 1;
+                let dst_x = (x as isize - jitter).clamp(0, self.width as isize - 1) as usize;
+                self.grid[y * self.width + dst_x] = new_intensity;
+            }
+        }
+    }
+
+    /// Renders the intensity grid into the offscreen buffer, then blits it
+    /// onto `buf` at `area`'s offset so the fire's own pixels never touch
+    /// the shared frame buffer directly.
+    fn render(&mut self, buf: &mut Buffer, area: Rect) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let Some(cell) = self.offscreen.cell_mut((x as u16, y as u16)) else {
+                    continue;
+                };
+                let intensity = self.grid[y * self.width + x];
+                cell.set_char(palette_glyph(intensity));
+                cell.set_fg(palette_color(intensity));
+            }
+        }
+
+        blit_buffer(
+            &self.offscreen,
+            buf,
+            Offset {
+                x: area.x as i32,
+                y: area.y as i32,
+            },
+        );
+    }
+}
+
+fn seed_bottom_row(grid: &mut [u8], width: usize, height: usize) {
+    if height == 0 {
+        return;
+    }
+    let bottom = (height - 1) * width;
+    grid[bottom..bottom + width].fill(MAX_INTENSITY);
+}
+
+/// Upper bound on the number of propagation ticks it takes the whole grid
+/// to decay to black under `fade_out`: the seed row empties out in
+/// `MAX_INTENSITY` ticks, and since rows are updated bottom-up in place the
+/// rows above go dark in that same tick; the extra `height` ticks are a
+/// margin.
+fn burn_out_ticks(height: usize) -> u32 {
+    (height + MAX_INTENSITY as usize) as u32
+}
+
+/// Builds a real fire effect over the cells of `area`. `fade_out` starves
+/// the seed row over time so the fire dies down instead of burning forever.
+///
+/// The returned `Effect` owns its intensity grid and renders into its own
+/// offscreen buffer before blitting onto the frame at `area`'s offset, so
+/// it can be dropped into an `EffectManager` exactly like any other effect
+/// without its area being tied to the surrounding page layout. Propagation
+/// is paced by the real elapsed time each tick (`ShaderFnContext::last_tick`),
+/// so it runs at the same speed regardless of the host's repaint cadence.
+///
+/// With `fade_out`, the effect's own duration is sized from
+/// `burn_out_ticks`, an upper bound on how long the grid takes to burn
+/// out, so `EffectManager` reclaims it shortly after it has visually gone
+/// dark instead of it lingering in the background. Without `fade_out` the
+/// effect's duration is one hour, so in practice the fire keeps burning
+/// until the caller clears it from the manager.
+pub fn doom_fire(area: Rect, fade_out: bool) -> Effect {
+    let state = FireState::new(area, fade_out);
+
+    let duration = if fade_out {
+        STEP_INTERVAL * burn_out_ticks(state.height)
+    } else {
+        Duration::from_secs(3600)
+    };
+
+    fx::effect_fn_buf(state, duration, move |state, ctx, buf| {
+        state.since_step += ctx.last_tick;
+        while state.since_step >= STEP_INTERVAL {
+            state.since_step -= STEP_INTERVAL;
+            state.step();
+        }
+        state.render(buf, area);
+    })
+    .with_area(area)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn palette_color_is_black_at_zero_and_brightest_at_max_intensity() {
+        assert_eq!(palette_color(0), Color::from_u32(0x07_0707));
+        assert_eq!(palette_color(MAX_INTENSITY), Color::from_u32(0xff_ff9f));
+    }
+
+    #[test]
+    fn palette_glyph_is_blank_at_zero_and_solid_at_max_intensity() {
+        assert_eq!(palette_glyph(0), ' ');
+        assert_eq!(palette_glyph(MAX_INTENSITY), '█');
+    }
+
+    #[test]
+    fn seed_bottom_row_seeds_to_max_intensity_and_leaves_rest_dark() {
+        let (width, height) = (4, 3);
+        let mut grid = vec![0u8; width * height];
+        seed_bottom_row(&mut grid, width, height);
+
+        let bottom = (height - 1) * width;
+        assert!(grid[bottom..bottom + width]
+            .iter()
+            .all(|&v| v == MAX_INTENSITY));
+        assert!(grid[..bottom].iter().all(|&v| v == 0));
+    }
+
+    #[test]
+    fn step_decays_the_seed_row_under_fade_out() {
+        let mut state = FireState::new(Rect::new(0, 0, 4, 3), true);
+        let bottom = (state.height - 1) * state.width;
+
+        state.step();
+
+        assert_eq!(state.grid[bottom], MAX_INTENSITY - 1);
+    }
+
+    #[test]
+    fn step_never_produces_intensity_above_max() {
+        let mut state = FireState::new(Rect::new(0, 0, 6, 5), false);
+        for _ in 0..burn_out_ticks(state.height) {
+            state.step();
+            assert!(state.grid.iter().all(|&v| v <= MAX_INTENSITY));
+        }
+    }
+
+    #[test]
+    fn burn_out_ticks_is_enough_to_fully_decay_the_grid() {
+        let mut state = FireState::new(Rect::new(0, 0, 6, 5), true);
+        for _ in 0..burn_out_ticks(state.height) {
+            state.step();
+        }
+
+        assert!(state.grid.iter().all(|&v| v == 0));
+    }
+}
@@ -0,0 +1,137 @@
+//! Effect gallery: a small registry of runnable effects bound to keys in
+//! `App::handle_events`. Every entry is built through [`build_effect`], so
+//! registering a new effect is a single `EffectKind` variant and match arm
+//! rather than another hand-rolled key handler.
+
+use ratatui::{
+    layout::{Margin, Offset, Rect},
+    style::{Color, Style},
+};
+
+use tachyonfx::{fx, fx::EvolveSymbolSet, pattern, CellFilter, Effect, Interpolation, Motion};
+
+use crate::doom_fire;
+
+const SCREEN_BG: Color = Color::Rgb(0x1D, 0x20, 0x21);
+const CONTENT_BG: Color = Color::Rgb(0x32, 0x30, 0x2F);
+
+/// The effects available in the gallery, one per key binding.
+#[derive(Clone, Copy, Debug)]
+pub enum EffectKind {
+    /// Ignition flash + the real propagating doom-fire (see [`doom_fire`]).
+    Fire,
+    /// Cells shrink in along an axis to reveal the content.
+    SlideIn,
+    /// Cells shrink out along an axis to hide the content.
+    SlideOut,
+    /// A coalesce-patterned dissolve reveal.
+    Dissolve,
+    /// A color gradient sweeping across the area.
+    ColorSweep,
+}
+
+/// Derives the area a gallery effect should target from the current frame
+/// size, rather than a fixed magic `Rect`.
+pub fn gallery_area(frame_area: Rect) -> Rect {
+    frame_area.inner(Margin::new(frame_area.width / 8, frame_area.height / 6))
+}
+
+/// Builds the effect for `kind` over `area`.
+pub fn build_effect(kind: EffectKind, area: Rect) -> Effect {
+    match kind {
+        EffectKind::Fire => fire(area),
+        EffectKind::SlideIn => slide_in(area),
+        EffectKind::SlideOut => slide_out(area),
+        EffectKind::Dissolve => dissolve(area),
+        EffectKind::ColorSweep => color_sweep(area),
+    }
+}
+
+fn fire(area: Rect) -> Effect {
+    let style = Style::default().fg(CONTENT_BG).bg(SCREEN_BG);
+
+    let boot_timer = (300, Interpolation::CircIn);
+    let timer = (900, Interpolation::QuadIn);
+
+    // Phase 1: Startup - Radial pattern evolve effect (ignition flash)
+    let startup = fx::evolve((EvolveSymbolSet::Shaded, style), boot_timer)
+        .with_pattern(pattern::RadialPattern::with_transition((0.5, 0.5), 10.0))
+        .with_area(area);
+
+    // Phase 2: Text Fade-In - Reveals text through the fire with coalesce pattern
+    let fade_in_text = fx::fade_from(SCREEN_BG, SCREEN_BG, timer)
+        .with_filter(CellFilter::Text)
+        .with_area(area)
+        .with_pattern(pattern::CoalescePattern::new());
+
+    let intro = fx::prolong_start(
+        300,
+        fx::sequence(&[
+            startup,
+            fx::parallel(&[fx::fade_from(SCREEN_BG, SCREEN_BG, 300), fade_in_text]),
+        ]),
+    );
+
+    // Phase 3: Real fire - a propagating doom-fire simulation running
+    // alongside the intro, rather than a canned evolve/translate reveal.
+    fx::parallel(&[intro, doom_fire::doom_fire(area, true)])
+}
+
+fn slide_in(area: Rect) -> Effect {
+    let timer = (500, Interpolation::QuadOut);
+    fx::slide_in(Motion::RightToLeft, area.width, 0, CONTENT_BG, timer).with_area(area)
+}
+
+fn slide_out(area: Rect) -> Effect {
+    let timer = (500, Interpolation::QuadIn);
+    fx::slide_out(Motion::LeftToRight, area.width, 0, CONTENT_BG, timer).with_area(area)
+}
+
+fn dissolve(area: Rect) -> Effect {
+    let timer = (700, Interpolation::SineOut);
+    fx::dissolve_to(Style::default().bg(CONTENT_BG), timer)
+        .with_pattern(pattern::CoalescePattern::new())
+        .with_area(area)
+}
+
+fn color_sweep(area: Rect) -> Effect {
+    let timer = (800, Interpolation::Linear);
+
+    // Reuses the coalesce-patterned `fade_from` of the fire intro's text
+    // reveal, translated horizontally across the full area.
+    let sweep = fx::fade_from(SCREEN_BG, CONTENT_BG, timer)
+        .with_filter(CellFilter::All)
+        .with_pattern(pattern::CoalescePattern::new());
+
+    fx::translate(
+        sweep,
+        Offset {
+            x: area.width as i32,
+            y: 0,
+        },
+        timer,
+    )
+    .with_area(area)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gallery_area_insets_by_an_eighth_width_and_a_sixth_height() {
+        let frame = Rect::new(0, 0, 80, 24);
+        let area = gallery_area(frame);
+
+        assert_eq!(area, Rect::new(10, 4, 60, 16));
+    }
+
+    #[test]
+    fn gallery_area_never_panics_on_a_tiny_frame() {
+        let frame = Rect::new(0, 0, 2, 2);
+        let area = gallery_area(frame);
+
+        assert!(area.width <= frame.width);
+        assert!(area.height <= frame.height);
+    }
+}
@@ -1,8 +1,14 @@
-use std::{cell::RefCell, io, rc::Rc, time::Duration};
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    io,
+    rc::Rc,
+    time::Duration,
+};
 
 use ratatui::{
-    layout::{Alignment, Offset, Rect},
-    style::{Color, Style, Stylize},
+    layout::{Alignment, Constraint, Layout, Rect},
+    style::{Color, Stylize},
     widgets::{Block, BorderType, Paragraph},
     Frame, Terminal,
 };
@@ -12,7 +18,20 @@ use ratzilla::{
     DomBackend, WebRenderer,
 };
 
-use tachyonfx::{fx, fx::EvolveSymbolSet, pattern, CellFilter, EffectManager, Interpolation};
+use tachyonfx::{buffer_to_ansi_string, widget::EffectTimeline, Effect, EffectManager};
+
+use wasm_bindgen::JsValue;
+
+use gallery::EffectKind;
+
+mod capture;
+mod doom_fire;
+mod gallery;
+
+/// Oldest captured frames are dropped once a capture recording reaches this
+/// many frames, so leaving capture running in a long-lived tab doesn't grow
+/// `App::captured_frames` without bound.
+const MAX_CAPTURED_FRAMES: usize = 600;
 
 fn main() -> io::Result<()> {
     let backend = DomBackend::new()?;
@@ -33,15 +52,79 @@ fn main() -> io::Result<()> {
     Ok(())
 }
 
-#[derive(Default)]
 struct App {
     counter: RefCell<u8>,
     effects: RefCell<EffectManager<()>>,
+    /// Toggled with 't'; renders an `EffectTimeline` panel alongside the
+    /// content so effect chains built from `fx::sequence`/`fx::parallel`/
+    /// `fx::prolong_start` can be inspected live.
+    debug_mode: RefCell<bool>,
+    /// The most recently built gallery effect, kept around (rather than
+    /// only handed to `EffectManager`) so the timeline panel has something
+    /// to introspect.
+    active_effect: RefCell<Option<Effect>>,
+    /// `performance.now()` timestamp (ms) of the previous `render` call, so
+    /// effect playback tracks real wall-clock time instead of an assumed
+    /// 16ms step.
+    last_tick_ms: RefCell<Option<f64>>,
+    /// Multiplier applied to the measured frame delta; 1.0 is real-time,
+    /// <1.0 plays effects in slow motion, >1.0 speeds them up.
+    speed_multiplier: Cell<f64>,
+    /// Content area of the most recently rendered frame (excluding the
+    /// timeline panel in debug mode), so key handlers can size gallery
+    /// effects from the real frame instead of a magic `Rect`.
+    last_area: RefCell<Rect>,
+    /// Whether post-effect frames are currently being captured as ANSI
+    /// strings, toggled with 'r'.
+    capturing: RefCell<bool>,
+    /// ANSI-escaped snapshots of each captured frame, oldest first, capped
+    /// at `MAX_CAPTURED_FRAMES` with the oldest frame dropped to make room.
+    captured_frames: RefCell<VecDeque<String>>,
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self {
+            counter: RefCell::default(),
+            effects: RefCell::default(),
+            debug_mode: RefCell::default(),
+            active_effect: RefCell::default(),
+            last_tick_ms: RefCell::default(),
+            speed_multiplier: Cell::new(1.0),
+            last_area: RefCell::default(),
+            capturing: RefCell::default(),
+            captured_frames: RefCell::default(),
+        }
+    }
 }
 
 impl App {
+    /// Computes the real elapsed time since the previous frame (scaled by
+    /// `speed_multiplier`), using the browser's `performance.now()` clock
+    /// rather than a fixed step, so effects run at the correct speed
+    /// regardless of the actual repaint cadence.
+    fn frame_delta(&self) -> Duration {
+        let now = web_sys::window()
+            .and_then(|window| window.performance())
+            .map(|performance| performance.now())
+            .unwrap_or(0.0);
+
+        let mut last_tick_ms = self.last_tick_ms.borrow_mut();
+        let delta_ms = match *last_tick_ms {
+            Some(previous) => (now - previous).max(0.0),
+            // First frame: no previous timestamp to diff against.
+            None => 16.0,
+        };
+        *last_tick_ms = Some(now);
+
+        Duration::from_secs_f64(delta_ms / 1000.0 * self.speed_multiplier.get())
+    }
+
     fn render(&self, frame: &mut Frame) {
         let counter = self.counter.borrow();
+        let speed = self.speed_multiplier.get();
+        let capturing = *self.capturing.borrow();
+        let frame_count = self.captured_frames.borrow().len();
         let block = Block::bordered()
             .title("rust-effects")
             .title_alignment(Alignment::Center)
@@ -51,7 +134,14 @@ impl App {
         let header_text = format!(
             r#"This is a Ratzilla template.
             Press left and right to increment and decrement the counter respectively.
-            Press 'f' to trigger fire effect.
+            Press 'f' to trigger the fire effect.
+            Press 's'/'o' to slide the content in/out.
+            Press 'd' for a dissolve reveal, 'w' for a color sweep.
+            Press 't' to toggle the effect timeline debug view.
+            Press '[' and ']' to slow down or speed up effect playback.
+            Press 'r' to toggle frame capture, 'e' to export captured frames.
+            Speed: {speed:.2}x
+            Capturing: {capturing} ({frame_count} frames)
             Counter: {counter}
             "#
         );
@@ -85,12 +175,43 @@ impl App {
             .bg(Color::Black)
             .centered();
 
-        frame.render_widget(paragraph, frame.area());
+        let (content_area, timeline_area) = if *self.debug_mode.borrow() {
+            let [content, timeline] =
+                Layout::horizontal([Constraint::Percentage(70), Constraint::Percentage(30)])
+                    .areas(frame.area());
+            (content, Some(timeline))
+        } else {
+            (frame.area(), None)
+        };
+
+        frame.render_widget(paragraph, content_area);
+        *self.last_area.borrow_mut() = content_area;
+
+        if let Some(timeline_area) = timeline_area {
+            let timeline_block = Block::bordered()
+                .title("effect timeline")
+                .border_type(BorderType::Rounded);
+            let inner_area = timeline_block.inner(timeline_area);
+            frame.render_widget(timeline_block, timeline_area);
 
-        let area = frame.area();
+            if let Some(effect) = self.active_effect.borrow().as_ref() {
+                let timeline = EffectTimeline::builder().effect(effect).build();
+                frame.render_widget(timeline, inner_area);
+            }
+        }
 
         let mut effects = self.effects.borrow_mut();
-        effects.process_effects(Duration::from_millis(16).into(), frame.buffer_mut(), area);
+        effects.process_effects(self.frame_delta().into(), frame.buffer_mut(), content_area);
+        drop(effects);
+
+        if *self.capturing.borrow() {
+            let ansi_frame = buffer_to_ansi_string(frame.buffer_mut(), false);
+            let mut captured_frames = self.captured_frames.borrow_mut();
+            if captured_frames.len() >= MAX_CAPTURED_FRAMES {
+                captured_frames.pop_front();
+            }
+            captured_frames.push_back(ansi_frame);
+        }
     }
 
     fn handle_events(&self, key_event: KeyEvent) {
@@ -100,60 +221,76 @@ impl App {
             KeyCode::Right => *counter = counter.saturating_add(1),
             KeyCode::Char('f') => {
                 drop(counter);
-                self.trigger_fire_effect();
+                self.trigger_effect(EffectKind::Fire);
+            }
+            KeyCode::Char('s') => {
+                drop(counter);
+                self.trigger_effect(EffectKind::SlideIn);
+            }
+            KeyCode::Char('o') => {
+                drop(counter);
+                self.trigger_effect(EffectKind::SlideOut);
+            }
+            KeyCode::Char('d') => {
+                drop(counter);
+                self.trigger_effect(EffectKind::Dissolve);
+            }
+            KeyCode::Char('w') => {
+                drop(counter);
+                self.trigger_effect(EffectKind::ColorSweep);
+            }
+            KeyCode::Char('t') => {
+                drop(counter);
+                let mut debug_mode = self.debug_mode.borrow_mut();
+                *debug_mode = !*debug_mode;
+            }
+            KeyCode::Char('[') => {
+                drop(counter);
+                self.speed_multiplier
+                    .set((self.speed_multiplier.get() - 0.25).max(0.25));
+            }
+            KeyCode::Char(']') => {
+                drop(counter);
+                self.speed_multiplier
+                    .set((self.speed_multiplier.get() + 0.25).min(4.0));
+            }
+            KeyCode::Char('r') => {
+                drop(counter);
+                let mut capturing = self.capturing.borrow_mut();
+                *capturing = !*capturing;
+            }
+            KeyCode::Char('e') => {
+                drop(counter);
+                self.export_captured_frames();
             }
             _ => {}
         }
     }
 
-    fn trigger_fire_effect(&self) {
-        // Calculate the area where the code snippet starts
-        // Adjust these values based on your layout:
-        // - x: horizontal offset from left edge
-        // - y: vertical offset (header takes ~5 lines)
-        // - width: width of the code block
-        // - height: height of the code block
-        let code_area = Rect::new(12, 12, 80, 17);
-
-        let screen_bg = Color::from_u32(0x1D2021);
-        let content_bg = Color::from_u32(0x32302F);
-
-        let style = Style::default().fg(content_bg).bg(screen_bg);
-
-        let boot_timer = (300, Interpolation::CircIn);
-        let timer = (900, Interpolation::QuadIn);
-
-        // Phase 1: Startup - Radial pattern evolve effect (fire ignition)
-        let startup = fx::evolve((EvolveSymbolSet::Shaded, style), boot_timer)
-            .with_pattern(pattern::RadialPattern::with_transition((0.5, 0.5), 10.0))
-            .with_area(code_area);
-
-        // Phase 2: Main Fire - Reversed evolve_from with coalesce pattern
-        let inner_fire_fx = fx::evolve_from((EvolveSymbolSet::Quadrants, style), timer)
-            .with_pattern(pattern::CoalescePattern::new())
-            .with_area(code_area)
-            .reversed();
-
-        // Translate the fire upward to simulate rising flames
-        let fire =
-            fx::translate(inner_fire_fx, Offset { x: 0, y: -22 }, timer).with_area(code_area);
-
-        // Phase 3: Text Fade-In - Reveals text through the fire with coalesce pattern
-        let fade_in_text = fx::fade_from(screen_bg, screen_bg, timer)
-            .with_filter(CellFilter::Text)
-            .with_area(code_area)
-            .with_pattern(pattern::CoalescePattern::new());
-
-        // Orchestrate all phases
-        let fire_effect = fx::prolong_start(
-            300,
-            fx::sequence(&[
-                startup,
-                fx::parallel(&[fx::fade_from(screen_bg, screen_bg, 300), fire, fade_in_text]),
-            ]),
-        );
+    /// Builds and plays a gallery effect, sized from the most recently
+    /// rendered frame area rather than a magic `Rect`.
+    fn trigger_effect(&self, kind: EffectKind) {
+        let area = gallery::gallery_area(*self.last_area.borrow());
+        let effect = gallery::build_effect(kind, area);
 
-        let mut effects = self.effects.borrow_mut();
-        effects.add_effect(fire_effect);
+        *self.active_effect.borrow_mut() = Some(effect.clone());
+        self.effects.borrow_mut().add_effect(effect);
+    }
+
+    /// Dumps the captured ANSI frames to the browser console and offers
+    /// them as a downloadable file, so a recording can be replayed in a
+    /// plain terminal with `println!` or embedded in docs.
+    fn export_captured_frames(&self) {
+        let frames = self.captured_frames.borrow();
+        if frames.is_empty() {
+            return;
+        }
+
+        let recording = frames.iter().cloned().collect::<Vec<_>>().join("\n");
+        web_sys::console::log_1(&JsValue::from_str(&recording));
+
+        if let Err(err) = capture::download_text_file("rust-effects-capture.ans", &recording) {
+            web_sys::console::error_1(&err);
+        }
     }
 }